@@ -11,12 +11,14 @@
 //!
 //! [`break`] from a loop if a given predicate evaluates to [`true`].
 //!
-//! Supports optionally providing a loop label to specify the loop from which to [`break`].
+//! Supports optionally providing a loop label and/or a value to [`break`] with.
 //! ```text
 //! use flow_control::break_if;
 //!
 //! break_if!(predicate);
 //! break_if!(predicate, label);
+//! break_if!(predicate, value);
+//! break_if!(predicate, label, value);
 //! ```
 //!
 //! ---
@@ -49,12 +51,148 @@
 //!
 //! ---
 //!
+//! [`break_flow!(...)`](crate::break_flow)
+//!
+//! [`break`] out of a loop that is propagating a [`ControlFlow`](std::ops::ControlFlow).
+//!
+//! On [`ControlFlow::Continue(c)`](std::ops::ControlFlow::Continue), evaluates to `c`.
+//! On [`ControlFlow::Break(b)`](std::ops::ControlFlow::Break), [`break`]s with `b`.
+//! ```text
+//! use flow_control::break_flow;
+//!
+//! let c = break_flow!(control_flow);
+//! let c = break_flow!(control_flow, label);
+//! ```
+//!
+//! ---
+//!
+//! [`return_flow!(...)`](crate::return_flow)
+//!
+//! [`return`] from a function that is propagating a [`ControlFlow`](std::ops::ControlFlow).
+//!
+//! On [`ControlFlow::Continue(c)`](std::ops::ControlFlow::Continue), evaluates to `c`.
+//! On [`ControlFlow::Break(b)`](std::ops::ControlFlow::Break), [`return`]s with `b`.
+//! ```text
+//! use flow_control::return_flow;
+//!
+//! let c = return_flow!(control_flow);
+//! ```
+//!
+//! ---
+//!
+//! [`break_if_let!(...)`](crate::break_if_let)
+//!
+//! [`break`] from a loop if a given pattern matches, binding the pattern's captures.
+//!
+//! Supports optionally providing a loop label and/or a value to [`break`] with.
+//! ```text
+//! use flow_control::break_if_let;
+//!
+//! break_if_let!(pattern = expr);
+//! break_if_let!(pattern = expr, label);
+//! break_if_let!(pattern = expr => value);
+//! break_if_let!(pattern = expr, label => value);
+//! ```
+//!
+//! ---
+//!
+//! [`continue_if_let!(...)`](crate::continue_if_let)
+//!
+//! [`continue`] to the next iteration of a loop if a given pattern matches, binding the pattern's captures.
+//!
+//! Supports optionally providing a loop label to specify the loop in which to [`continue`].
+//! ```text
+//! use flow_control::continue_if_let;
+//!
+//! continue_if_let!(pattern = expr);
+//! continue_if_let!(pattern = expr, label);
+//! ```
+//!
+//! ---
+//!
+//! [`return_if_let!(...)`](crate::return_if_let)
+//!
+//! [`return`] from a function if a given pattern matches, binding the pattern's captures.
+//!
+//! Supports optionally providing a value to [`return`].
+//! ```text
+//! use flow_control::return_if_let;
+//!
+//! return_if_let!(pattern = expr);
+//! return_if_let!(pattern = expr => value);
+//! ```
+//!
+//! ---
+//!
+//! [`flow_block! { ... }`](crate::flow_block)
+//!
+//! Wraps a sequence of statements punctuated by [`label!`](crate::label) markers, and allows
+//! [`goto!`](crate::goto) to jump forward to one of those markers.
+//! ```text
+//! use flow_control::{flow_block, goto, label};
+//!
+//! flow_block! {
+//!     ...
+//!     goto!(name);
+//!     ...
+//!     label!(name);
+//!     ...
+//! };
+//! ```
+//!
+//! ---
+//!
+//! [`break_unless!(...)`](crate::break_unless)
+//!
+//! [`break`] from a loop if a given predicate evaluates to [`false`].
+//!
+//! Supports optionally providing a loop label and/or a value to [`break`] with.
+//! ```text
+//! use flow_control::break_unless;
+//!
+//! break_unless!(predicate);
+//! break_unless!(predicate, label);
+//! break_unless!(predicate, value);
+//! break_unless!(predicate, label, value);
+//! ```
+//!
+//! ---
+//!
+//! [`continue_unless!(...)`](crate::continue_unless)
+//!
+//! [`continue`] to the next iteration of a loop if a given predicate evaluates to [`false`].
+//!
+//! Supports optionally providing a loop label to specify the loop in which to [`continue`].
+//! ```text
+//! use flow_control::continue_unless;
+//!
+//! continue_unless!(predicate);
+//! continue_unless!(predicate, label);
+//! ```
+//!
+//! ---
+//!
+//! [`return_unless!(...)`](crate::return_unless)
+//!
+//! [`return`] from a function if a given predicate evaluates to [`false`].
+//!
+//! Supports optionally providing a value to [`return`].
+//! ```text
+//! use flow_control::return_unless;
+//!
+//! return_unless!(predicate);
+//! return_unless!(predicate, value);
+//! ```
+//!
+//! ---
+//!
 
 /// [`break`]: https://doc.rust-lang.org/std/keyword.break.html
 ///
 /// [`break`] from a loop if a given predicate evaluates to [`true`].
 ///
-/// Supports optionally providing a loop label to specify the loop from which to [`break`].
+/// Supports optionally providing a loop label to specify the loop from which to [`break`],
+/// and/or a value for the loop expression to evaluate to.
 ///
 /// # Usage
 ///
@@ -62,6 +200,10 @@
 ///
 /// [`break_if!`](crate::break_if)`(predicate, label)`
 ///
+/// [`break_if!`](crate::break_if)`(predicate, value)`
+///
+/// [`break_if!`](crate::break_if)`(predicate, label, value)`
+///
 /// # Examples
 ///
 /// #### Predicate only
@@ -102,6 +244,30 @@
 ///     vec![(1, 1), (1, 2)],
 /// );
 /// ```
+///
+/// #### Predicate and value
+/// ```
+/// use flow_control::break_if;
+///
+/// let result = loop {
+///     break_if!(true, "done");
+/// };
+///
+/// assert_eq!(result, "done");
+/// ```
+///
+/// #### Predicate, label, and value
+/// ```
+/// use flow_control::break_if;
+///
+/// let result = 'outer: loop {
+///     loop {
+///         break_if!(true, 'outer, "done");
+///     }
+/// };
+///
+/// assert_eq!(result, "done");
+/// ```
 #[macro_export]
 macro_rules! break_if {
     ($predicate:expr $(,)?) => {
@@ -114,6 +280,16 @@ macro_rules! break_if {
             break $label;
         }
     };
+    ($predicate:expr, $label:tt, $value:expr $(,)?) => {
+        if $predicate {
+            break $label $value;
+        }
+    };
+    ($predicate:expr, $value:expr $(,)?) => {
+        if $predicate {
+            break $value;
+        }
+    };
 }
 
 /// [`continue`]: https://doc.rust-lang.org/std/keyword.continue.html
@@ -240,3 +416,845 @@ macro_rules! return_if {
         }
     };
 }
+
+/// [`break`]: https://doc.rust-lang.org/std/keyword.break.html
+///
+/// [`break`] out of a loop that is propagating a [`ControlFlow`](std::ops::ControlFlow).
+///
+/// On [`ControlFlow::Continue(c)`](std::ops::ControlFlow::Continue), evaluates to `c`.
+///
+/// On [`ControlFlow::Break(b)`](std::ops::ControlFlow::Break), [`break`]s with `b`.
+///
+/// Supports optionally providing a loop label to specify the loop from which to [`break`].
+///
+/// # Usage
+///
+/// [`break_flow!`](crate::break_flow)`(expr)`
+///
+/// [`break_flow!`](crate::break_flow)`(expr, label)`
+///
+/// # Examples
+///
+/// #### Expression only
+/// ```
+/// use flow_control::break_flow;
+/// use std::ops::ControlFlow;
+///
+/// let mut v = Vec::new();
+/// let step = |n: i32| -> ControlFlow<&'static str, i32> {
+///     if n == 3 {
+///         ControlFlow::Break("stopped")
+///     } else {
+///         ControlFlow::Continue(n)
+///     }
+/// };
+///
+/// let result = loop {
+///     let n = break_flow!(step(v.len() as i32));
+///     v.push(n);
+/// };
+///
+/// assert_eq!(v, vec![0, 1, 2]);
+/// assert_eq!(result, "stopped");
+/// ```
+///
+/// #### Expression and label
+/// ```
+/// use flow_control::break_flow;
+/// use std::ops::ControlFlow;
+///
+/// let mut v = Vec::new();
+/// let step = |n: i32| -> ControlFlow<&'static str, i32> {
+///     if n == 3 {
+///         ControlFlow::Break("stopped")
+///     } else {
+///         ControlFlow::Continue(n)
+///     }
+/// };
+///
+/// let result = 'outer: loop {
+///     loop {
+///         let n = break_flow!(step(v.len() as i32), 'outer);
+///         v.push(n);
+///     }
+/// };
+///
+/// assert_eq!(v, vec![0, 1, 2]);
+/// assert_eq!(result, "stopped");
+/// ```
+#[macro_export]
+macro_rules! break_flow {
+    ($expr:expr $(,)?) => {
+        match $expr {
+            ::std::ops::ControlFlow::Continue(c) => c,
+            ::std::ops::ControlFlow::Break(b) => break b,
+        }
+    };
+    ($expr:expr, $label:tt $(,)?) => {
+        match $expr {
+            ::std::ops::ControlFlow::Continue(c) => c,
+            ::std::ops::ControlFlow::Break(b) => break $label b,
+        }
+    };
+}
+
+/// [`return`]: https://doc.rust-lang.org/std/keyword.return.html
+///
+/// [`return`] from a function that is propagating a [`ControlFlow`](std::ops::ControlFlow).
+///
+/// On [`ControlFlow::Continue(c)`](std::ops::ControlFlow::Continue), evaluates to `c`.
+///
+/// On [`ControlFlow::Break(b)`](std::ops::ControlFlow::Break), [`return`]s with `b`.
+///
+/// # Usage
+///
+/// [`return_flow!`](crate::return_flow)`(expr)`
+///
+/// # Examples
+///
+/// ```
+/// use flow_control::return_flow;
+/// use std::ops::ControlFlow;
+///
+/// let find_first_multiple_of_three = || {
+///     let mut v = Vec::new();
+///     for n in 1..10 {
+///         let step: ControlFlow<i32, i32> = if n % 3 == 0 {
+///             ControlFlow::Break(n)
+///         } else {
+///             ControlFlow::Continue(n)
+///         };
+///         v.push(return_flow!(step));
+///     }
+///     unreachable!()
+/// };
+///
+/// assert_eq!(find_first_multiple_of_three(), 3);
+/// ```
+#[macro_export]
+macro_rules! return_flow {
+    ($expr:expr $(,)?) => {
+        match $expr {
+            ::std::ops::ControlFlow::Continue(c) => c,
+            ::std::ops::ControlFlow::Break(b) => return b,
+        }
+    };
+}
+
+/// [`break`]: https://doc.rust-lang.org/std/keyword.break.html
+///
+/// [`break`] from a loop if a given pattern matches an expression, binding the pattern's captures.
+///
+/// Supports optionally providing a loop label to specify the loop from which to [`break`],
+/// and/or a value for the loop expression to evaluate to.
+///
+/// # Usage
+///
+/// [`break_if_let!`](crate::break_if_let)`(pattern = expr)`
+///
+/// [`break_if_let!`](crate::break_if_let)`(pattern = expr, label)`
+///
+/// [`break_if_let!`](crate::break_if_let)`(pattern = expr => value)`
+///
+/// [`break_if_let!`](crate::break_if_let)`(pattern = expr, label => value)`
+///
+/// # Examples
+///
+/// #### Pattern only
+/// ```
+/// use flow_control::break_if_let;
+///
+/// let mut v = Vec::new();
+/// for item in [Some(1), Some(2), None, Some(4)] {
+///     break_if_let!(None = item);
+///     v.push(item.unwrap());
+/// }
+///
+/// assert_eq!(v, vec![1, 2]);
+/// ```
+///
+/// #### Pattern and label
+/// ```
+/// use flow_control::break_if_let;
+///
+/// let mut v = Vec::new();
+/// 'outer: for outer_n in 1..3 {
+///     for inner_n in [Some(1), None, Some(3)] {
+///         break_if_let!(None = inner_n, 'outer);
+///         v.push((outer_n, inner_n));
+///     }
+/// }
+///
+/// assert_eq!(v, vec![(1, Some(1))]);
+/// ```
+///
+/// #### Pattern and value
+/// ```
+/// use flow_control::break_if_let;
+///
+/// let result = loop {
+///     break_if_let!(Some(n) = Some(7) => n * 2);
+/// };
+///
+/// assert_eq!(result, 14);
+/// ```
+///
+/// #### Pattern, label, and value
+/// ```
+/// use flow_control::break_if_let;
+///
+/// let result = 'outer: loop {
+///     loop {
+///         break_if_let!(Some(n) = Some(7), 'outer => n * 2);
+///     }
+/// };
+///
+/// assert_eq!(result, 14);
+/// ```
+#[macro_export]
+macro_rules! break_if_let {
+    ($pattern:pat = $expr:expr $(,)?) => {
+        if let $pattern = $expr {
+            break;
+        }
+    };
+    ($pattern:pat = $expr:expr, $label:tt $(,)?) => {
+        if let $pattern = $expr {
+            break $label;
+        }
+    };
+    ($pattern:pat = $expr:expr => $value:expr $(,)?) => {
+        if let $pattern = $expr {
+            break $value;
+        }
+    };
+    ($pattern:pat = $expr:expr, $label:tt => $value:expr $(,)?) => {
+        if let $pattern = $expr {
+            break $label $value;
+        }
+    };
+}
+
+/// [`continue`]: https://doc.rust-lang.org/std/keyword.continue.html
+///
+/// [`continue`] to the next iteration of a loop if a given pattern matches an expression, binding the pattern's captures.
+///
+/// Supports optionally providing a loop label to specify the loop in which to [`continue`].
+///
+/// # Usage
+///
+/// [`continue_if_let!`](crate::continue_if_let)`(pattern = expr)`
+///
+/// [`continue_if_let!`](crate::continue_if_let)`(pattern = expr, label)`
+///
+/// # Examples
+///
+/// #### Pattern only
+/// ```
+/// use flow_control::continue_if_let;
+///
+/// let mut v = Vec::new();
+/// for item in [Some(1), None, Some(3)] {
+///     continue_if_let!(None = item);
+///     v.push(item.unwrap());
+/// }
+///
+/// assert_eq!(v, vec![1, 3]);
+/// ```
+///
+/// #### Pattern and label
+/// ```
+/// use flow_control::continue_if_let;
+///
+/// let mut v = Vec::new();
+/// 'outer: for outer_n in 1..3 {
+///     for inner_item in [Some(1), None, Some(3)] {
+///         continue_if_let!(None = inner_item, 'outer);
+///         v.push((outer_n, inner_item.unwrap()));
+///     }
+/// }
+///
+/// assert_eq!(v, vec![(1, 1), (2, 1)]);
+/// ```
+#[macro_export]
+macro_rules! continue_if_let {
+    ($pattern:pat = $expr:expr $(,)?) => {
+        if let $pattern = $expr {
+            continue;
+        }
+    };
+    ($pattern:pat = $expr:expr, $label:tt $(,)?) => {
+        if let $pattern = $expr {
+            continue $label;
+        }
+    };
+}
+
+/// [`return`]: https://doc.rust-lang.org/std/keyword.return.html
+///
+/// [`return`] from a function if a given pattern matches an expression, binding the pattern's captures.
+///
+/// Supports optionally providing a value to [`return`].
+///
+/// # Usage
+///
+/// [`return_if_let!`](crate::return_if_let)`(pattern = expr)`
+///
+/// [`return_if_let!`](crate::return_if_let)`(pattern = expr => value)`
+///
+/// # Examples
+///
+/// #### Pattern only
+/// ```
+/// use flow_control::return_if_let;
+///
+/// let mut v = Vec::new();
+/// (|| {
+///     for item in [Some(1), Some(2), None, Some(4)] {
+///         return_if_let!(None = item);
+///         v.push(item.unwrap());
+///     }
+/// })();
+///
+/// assert_eq!(v, vec![1, 2]);
+/// ```
+///
+/// #### Pattern and value
+/// ```
+/// use flow_control::return_if_let;
+///
+/// let find_error = |results: Vec<Result<i32, &'static str>>| {
+///     for result in results {
+///         return_if_let!(Err(e) = result => Err(e));
+///     }
+///     Ok(())
+/// };
+///
+/// assert_eq!(find_error(vec![Ok(1), Err("boom"), Ok(3)]), Err("boom"));
+/// ```
+#[macro_export]
+macro_rules! return_if_let {
+    ($pattern:pat = $expr:expr $(,)?) => {
+        if let $pattern = $expr {
+            return;
+        }
+    };
+    ($pattern:pat = $expr:expr => $value:expr $(,)?) => {
+        if let $pattern = $expr {
+            return $value;
+        }
+    };
+}
+
+/// A forward-jump marker for use inside [`flow_block! { ... }`](crate::flow_block).
+///
+/// `label!(name)` has no meaning on its own; [`flow_block!`](crate::flow_block) recognizes it
+/// as a marker and rewrites the surrounding block into a state machine. See
+/// [`flow_block!`](crate::flow_block) for details and examples.
+#[macro_export]
+macro_rules! label {
+    ($name:ident $(,)?) => {
+        compile_error!("`label!` can only be used inside `flow_control::flow_block! { ... }`");
+    };
+}
+
+/// A forward jump to a [`label!`](crate::label) marker, for use inside
+/// [`flow_block! { ... }`](crate::flow_block).
+///
+/// `goto!(name)` has no meaning on its own; [`flow_block!`](crate::flow_block) recognizes it
+/// as a marker and rewrites the surrounding block into a state machine. See
+/// [`flow_block!`](crate::flow_block) for details and examples.
+#[macro_export]
+macro_rules! goto {
+    ($name:ident $(,)?) => {
+        compile_error!("`goto!` can only be used inside `flow_control::flow_block! { ... }`");
+    };
+}
+
+/// Wraps a sequence of statements punctuated by [`label!`](crate::label) markers, so that
+/// [`goto!`](crate::goto) can jump forward to one of those markers.
+///
+/// Rust has no native `goto`, and arbitrary/backward jumps are unsound, so this only ever
+/// jumps forward within the same [`flow_block!`](crate::flow_block). The block is lowered into
+/// a small state machine: each [`label!`](crate::label) introduces a new state, and
+/// [`goto!(name)`](crate::goto) sets the state to `name`'s and restarts the dispatch loop.
+/// Falling off the end of a state simply advances into the next one, and the final state
+/// ends the block.
+///
+/// [`goto!(name)`](crate::goto) is checked at compile time against the labels in the same
+/// [`flow_block!`](crate::flow_block): a target that doesn't exist, or one that was already
+/// passed (a backward or self jump), is a compile error instead of an infinite loop at
+/// runtime.
+///
+/// Because each state is dispatched from its own `match` arm, a variable declared in one
+/// state is not in scope in a later state; only bindings from outside the
+/// [`flow_block!`](crate::flow_block) (or declared earlier in the *same* state) are visible
+/// across a [`goto!`](crate::goto). Statements skipped over by a [`goto!`](crate::goto) are
+/// unreachable by construction, so this macro allows the `unreachable_code` lint within the
+/// expansion.
+///
+/// [`goto!`](crate::goto) and [`label!`](crate::label) are only recognized directly in the
+/// statement sequence of [`flow_block!`](crate::flow_block) itself, not inside a nested block
+/// such as an `if` or `for` body; guard a [`goto!`](crate::goto) with a preceding
+/// [`break_if!`](crate::break_if)-style check on a plain `bool` instead of nesting it.
+///
+/// # Usage
+///
+/// [`flow_block!`](crate::flow_block)` { ... goto!(name); ... label!(name); ... }`
+///
+/// # Examples
+///
+/// #### Jump forward, skipping a region
+/// ```
+/// use flow_control::{flow_block, goto, label};
+///
+/// let mut v = Vec::new();
+/// flow_block! {
+///     v.push(1);
+///     goto!(cleanup);
+///     v.push(2);
+///     label!(cleanup);
+///     v.push(3);
+/// };
+///
+/// assert_eq!(v, vec![1, 3]);
+/// ```
+///
+/// #### Cleanup-style jump to the end
+/// ```
+/// use flow_control::{flow_block, goto, label};
+///
+/// fn run(items: &[i32]) -> &'static str {
+///     let mut result = "ok";
+///     flow_block! {
+///         result = "processing";
+///         goto!(cleanup);
+///         result = "unreachable";
+///         label!(cleanup);
+///         result = if items.is_empty() { "empty" } else { "processing" };
+///     };
+///     result
+/// }
+///
+/// assert_eq!(run(&[1, 2, 3]), "processing");
+/// assert_eq!(run(&[]), "empty");
+/// ```
+#[macro_export]
+macro_rules! flow_block {
+    ($($body:tt)*) => {
+        $crate::__flow_control_flow_block_labels!([] [$($body)*] $($body)*)
+    };
+}
+
+// Phase 1: scan the whole block up front for its ordered list of `label!` names, so that
+// phase 2 can check each `goto!` target against the full set (does it exist at all?) and
+// against the prefix already emitted (has it already been passed, i.e. is this a backward
+// jump?) instead of discovering either only as an opaque runtime infinite loop or a generic
+// "no variant found" error.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __flow_control_flow_block_labels {
+    ([$($all:ident)*] [$($body:tt)*]) => {
+        $crate::__flow_control_flow_block!(@arms
+            'flow_control_block __state __State
+            [$($all)*] [] [] __Start [] $($body)*
+        )
+    };
+    ([$($all:ident)*] [$($body:tt)*] label ! ( $name:ident ) ; $($rest:tt)*) => {
+        $crate::__flow_control_flow_block_labels!([$($all)* $name] [$($body)*] $($rest)*)
+    };
+    ([$($all:ident)*] [$($body:tt)*] $_tt:tt $($rest:tt)*) => {
+        $crate::__flow_control_flow_block_labels!([$($all)*] [$($body)*] $($rest)*)
+    };
+}
+
+// Phase 2: emit the state machine. `$all` is the full (fixed) label set from phase 1, and
+// `$seen` accumulates the labels already emitted so far, so that each `goto!` can be checked
+// against both.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __flow_control_flow_block {
+    (@arms
+        $label:lifetime $state:ident $State:ident
+        [$($all:ident)*]
+        [$($seen:ident)*]
+        [$($arms:tt)*]
+        $cur:ident
+        [$($seg:tt)*]
+    ) => {
+        {
+            #[allow(non_camel_case_types)]
+            enum $State { __Start, $($all),* }
+            let mut $state = $State::__Start;
+            $label: loop {
+                match $state {
+                    $($arms)*
+                    #[allow(unreachable_code)]
+                    $State::$cur => {
+                        {
+                            $($seg)*
+                        }
+                        break $label;
+                    }
+                }
+            }
+        }
+    };
+    (@arms
+        $label:lifetime $state:ident $State:ident
+        [$($all:ident)*]
+        [$($seen:ident)*]
+        [$($arms:tt)*]
+        $cur:ident
+        [$($seg:tt)*]
+        label ! ( $name:ident ) ; $($rest:tt)*
+    ) => {
+        $crate::__flow_control_flow_block!(@arms
+            $label $state $State
+            [$($all)*]
+            [$($seen)* $name]
+            [
+                $($arms)*
+                #[allow(unreachable_code)]
+                $State::$cur => {
+                    {
+                        $($seg)*
+                    }
+                    $state = $State::$name;
+                    continue $label;
+                }
+            ]
+            $name
+            []
+            $($rest)*
+        )
+    };
+    (@arms
+        $label:lifetime $state:ident $State:ident
+        [$($all:ident)*]
+        [$($seen:ident)*]
+        [$($arms:tt)*]
+        $cur:ident
+        [$($seg:tt)*]
+        goto ! ( $name:ident ) ; $($rest:tt)*
+    ) => {
+        $crate::__flow_control_flow_block!(@arms
+            $label $state $State
+            [$($all)*]
+            [$($seen)*]
+            [$($arms)*]
+            $cur
+            [
+                $($seg)*
+                const _: () = {
+                    if !$crate::__flow_control_label_in!($name; $($all)*) {
+                        panic!(concat!(
+                            "goto!(", stringify!($name), "): no label!(", stringify!($name),
+                            ") exists in this flow_block!"
+                        ));
+                    }
+                    if $crate::__flow_control_label_in!($name; $($seen)*) {
+                        panic!(concat!(
+                            "goto!(", stringify!($name), "): label!(", stringify!($name),
+                            ") was already passed; only forward jumps are supported"
+                        ));
+                    }
+                };
+                $state = $State::$name;
+                continue $label;
+            ]
+            $($rest)*
+        )
+    };
+    (@arms
+        $label:lifetime $state:ident $State:ident
+        [$($all:ident)*]
+        [$($seen:ident)*]
+        [$($arms:tt)*]
+        $cur:ident
+        [$($seg:tt)*]
+        $tt:tt $($rest:tt)*
+    ) => {
+        $crate::__flow_control_flow_block!(@arms
+            $label $state $State
+            [$($all)*]
+            [$($seen)*]
+            [$($arms)*]
+            $cur
+            [$($seg)* $tt]
+            $($rest)*
+        )
+    };
+}
+
+/// Byte-wise equality between two identifier names rendered via [`stringify!`], used to check
+/// [`goto!`](crate::goto) targets against the labels seen so far at compile time.
+#[doc(hidden)]
+pub const fn __flow_control_names_eq(a: &str, b: &str) -> bool {
+    let a = a.as_bytes();
+    let b = b.as_bytes();
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Expands to a `bool` expression that is `true` if `$name` textually matches any identifier
+/// in the given list, for use in a `const` check inside [`flow_block!`](crate::flow_block).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __flow_control_label_in {
+    ($name:ident; ) => {
+        false
+    };
+    ($name:ident; $head:ident $($tail:ident)*) => {
+        $crate::__flow_control_names_eq(stringify!($name), stringify!($head))
+            || $crate::__flow_control_label_in!($name; $($tail)*)
+    };
+}
+
+/// [`break`]: https://doc.rust-lang.org/std/keyword.break.html
+///
+/// [`break`] from a loop unless a given predicate evaluates to [`true`].
+///
+/// The predicate is negated as a whole, so `break_unless!(a && b)` expands to
+/// `if !(a && b) { break; }`, not `if !a && b { break; }`.
+///
+/// Supports optionally providing a loop label to specify the loop from which to [`break`],
+/// and/or a value for the loop expression to evaluate to.
+///
+/// # Usage
+///
+/// [`break_unless!`](crate::break_unless)`(predicate)`
+///
+/// [`break_unless!`](crate::break_unless)`(predicate, label)`
+///
+/// [`break_unless!`](crate::break_unless)`(predicate, value)`
+///
+/// [`break_unless!`](crate::break_unless)`(predicate, label, value)`
+///
+/// # Examples
+///
+/// #### Predicate only
+/// ```
+/// use flow_control::break_unless;
+///
+/// let mut v = Vec::new();
+/// for outer_n in 1..3 {
+///     for inner_n in 1..5 {
+///         break_unless!(inner_n < 3);
+///         v.push((outer_n, inner_n));
+///     }
+/// }
+///
+/// assert_eq!(
+///     v,
+///     vec![
+///         (1, 1), (1, 2),
+///         (2, 1), (2, 2),
+///     ]
+/// );
+/// ```
+///
+/// #### Predicate and label
+/// ```
+/// use flow_control::break_unless;
+///
+/// let mut v = Vec::new();
+/// 'outer: for outer_n in 1..3 {
+///     for inner_n in 1..5 {
+///         break_unless!(inner_n < 3, 'outer);
+///         v.push((outer_n, inner_n));
+///     }
+/// }
+///
+/// assert_eq!(
+///     v,
+///     vec![(1, 1), (1, 2)],
+/// );
+/// ```
+///
+/// #### Predicate and value
+/// ```
+/// use flow_control::break_unless;
+///
+/// let result = loop {
+///     break_unless!(false, "done");
+/// };
+///
+/// assert_eq!(result, "done");
+/// ```
+///
+/// #### Predicate, label, and value
+/// ```
+/// use flow_control::break_unless;
+///
+/// let result = 'outer: loop {
+///     loop {
+///         break_unless!(false, 'outer, "done");
+///     }
+/// };
+///
+/// assert_eq!(result, "done");
+/// ```
+#[macro_export]
+macro_rules! break_unless {
+    ($predicate:expr $(,)?) => {
+        if !($predicate) {
+            break;
+        }
+    };
+    ($predicate:expr, $label:tt $(,)?) => {
+        if !($predicate) {
+            break $label;
+        }
+    };
+    ($predicate:expr, $label:tt, $value:expr $(,)?) => {
+        if !($predicate) {
+            break $label $value;
+        }
+    };
+    ($predicate:expr, $value:expr $(,)?) => {
+        if !($predicate) {
+            break $value;
+        }
+    };
+}
+
+/// [`continue`]: https://doc.rust-lang.org/std/keyword.continue.html
+///
+/// [`continue`] to the next iteration of a loop unless a given predicate evaluates to [`true`].
+///
+/// The predicate is negated as a whole, so `continue_unless!(a && b)` expands to
+/// `if !(a && b) { continue; }`, not `if !a && b { continue; }`.
+///
+/// Supports optionally providing a loop label to specify the loop in which to [`continue`].
+///
+/// # Usage
+///
+/// [`continue_unless!`](crate::continue_unless)`(predicate)`
+///
+/// [`continue_unless!`](crate::continue_unless)`(predicate, label)`
+///
+/// # Examples
+///
+/// #### Predicate only
+/// ```
+/// use flow_control::continue_unless;
+///
+/// let mut v = Vec::new();
+/// for outer_n in 1..3 {
+///     for inner_n in 1..5 {
+///         continue_unless!(inner_n != 3);
+///         v.push((outer_n, inner_n));
+///     }
+/// }
+///
+/// assert_eq!(
+///     v,
+///     vec![
+///         (1, 1), (1, 2), (1, 4),
+///         (2, 1), (2, 2), (2, 4),
+///     ]
+/// );
+/// ```
+///
+/// #### Predicate and label
+/// ```
+/// use flow_control::continue_unless;
+///
+/// let mut v = Vec::new();
+/// 'outer: for outer_n in 1..3 {
+///     for inner_n in 1..5 {
+///         continue_unless!(inner_n != 3, 'outer);
+///         v.push((outer_n, inner_n));
+///     }
+/// }
+///
+/// assert_eq!(
+///     v,
+///     vec![
+///         (1, 1), (1, 2),
+///         (2, 1), (2, 2),
+///     ]
+/// );
+/// ```
+#[macro_export]
+macro_rules! continue_unless {
+    ($predicate:expr $(,)?) => {
+        if !($predicate) {
+            continue;
+        }
+    };
+    ($predicate:expr, $label:tt $(,)?) => {
+        if !($predicate) {
+            continue $label;
+        }
+    };
+}
+
+/// [`return`]: https://doc.rust-lang.org/std/keyword.return.html
+///
+/// [`return`] from a function unless a given predicate evaluates to [`true`].
+///
+/// The predicate is negated as a whole, so `return_unless!(a && b)` expands to
+/// `if !(a && b) { return; }`, not `if !a && b { return; }`.
+///
+/// Supports optionally providing a value to [`return`].
+///
+/// # Usage
+///
+/// [`return_unless!`](crate::return_unless)`(predicate)`
+///
+/// [`return_unless!`](crate::return_unless)`(predicate, value)`
+///
+/// # Examples
+///
+/// #### Default return
+/// ```
+/// use flow_control::return_unless;
+///
+/// let mut v = Vec::new();
+/// (|| {
+///     for n in 1..10 {
+///         return_unless!(n != 5);
+///         v.push(n)
+///     }
+/// })();
+///
+/// assert_eq!(v, vec![1, 2, 3, 4]);
+/// ```
+///
+/// #### Return a specified value
+/// ```
+/// use flow_control::return_unless;
+///
+/// let get_value = || {
+///     for n in 1..10 {
+///         return_unless!(n != 5, "early return");
+///     }
+///     return "return after loop";
+/// };
+///
+/// assert_eq!(get_value(), "early return");
+/// ```
+#[macro_export]
+macro_rules! return_unless {
+    ($predicate:expr $(,)?) => {
+        if !($predicate) {
+            return;
+        }
+    };
+    ($predicate:expr, $ret:expr $(,)?) => {
+        if !($predicate) {
+            return $ret;
+        }
+    };
+}